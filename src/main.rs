@@ -12,8 +12,132 @@ Todo:
 */
 
 use anyhow::{anyhow, ensure, Context, Result};
-use proj::Proj;
-use std::{env, fmt, process};
+use std::{
+    env, fmt,
+    io::{self, BufRead},
+    panic, process,
+};
+
+// WGS84 ellipsoid constants, used by the transverse-Mercator math below.
+const WGS84_A: f64 = 6_378_137.0;
+const WGS84_ECC_SQ: f64 = 0.00669438;
+const UTM_K0: f64 = 0.9996;
+
+fn utm_central_meridian_deg(zone: u8) -> f64 {
+    (zone as f64 - 1.0) * 6.0 - 180.0 + 3.0
+}
+
+// Forward transverse-Mercator: WGS84 lon/lat (degrees) -> UTM easting/northing
+// (meters) in the given zone. Standard Snyder/USGS series, the same family as
+// the inverse series below.
+fn forward_transverse_mercator(lon: f64, lat: f64, zone: u8) -> (f64, f64) {
+    let ecc_sq = WGS84_ECC_SQ;
+    let ecc_prime_sq = ecc_sq / (1.0 - ecc_sq);
+
+    let lat_rad = lat.to_radians();
+    let lon_rad = lon.to_radians();
+    let lon0_rad = utm_central_meridian_deg(zone).to_radians();
+
+    let n = WGS84_A / (1.0 - ecc_sq * lat_rad.sin().powi(2)).sqrt();
+    let t = lat_rad.tan().powi(2);
+    let c = ecc_prime_sq * lat_rad.cos().powi(2);
+    let a = lat_rad.cos() * (lon_rad - lon0_rad);
+
+    let m = WGS84_A
+        * ((1.0 - ecc_sq / 4.0 - 3.0 * ecc_sq.powi(2) / 64.0 - 5.0 * ecc_sq.powi(3) / 256.0) * lat_rad
+            - (3.0 * ecc_sq / 8.0 + 3.0 * ecc_sq.powi(2) / 32.0 + 45.0 * ecc_sq.powi(3) / 1024.0)
+                * (2.0 * lat_rad).sin()
+            + (15.0 * ecc_sq.powi(2) / 256.0 + 45.0 * ecc_sq.powi(3) / 1024.0) * (4.0 * lat_rad).sin()
+            - (35.0 * ecc_sq.powi(3) / 3072.0) * (6.0 * lat_rad).sin());
+
+    let easting = UTM_K0
+        * n
+        * (a + (1.0 - t + c) * a.powi(3) / 6.0
+            + (5.0 - 18.0 * t + t.powi(2) + 72.0 * c - 58.0 * ecc_prime_sq) * a.powi(5) / 120.0)
+        + 500_000.0;
+
+    let mut northing = UTM_K0
+        * (m + n
+            * lat_rad.tan()
+            * (a.powi(2) / 2.0
+                + (5.0 - t + 9.0 * c + 4.0 * c.powi(2)) * a.powi(4) / 24.0
+                + (61.0 - 58.0 * t + t.powi(2) + 600.0 * c - 330.0 * ecc_prime_sq) * a.powi(6) / 720.0));
+
+    if lat < 0.0 {
+        northing += 10_000_000.0;
+    }
+
+    (easting, northing)
+}
+
+// Inverse transverse-Mercator: UTM easting/northing (meters) in the given
+// zone/hemisphere -> WGS84 lon/lat (degrees).
+fn inverse_transverse_mercator(x: f64, y: f64, zone: u8, hemi: Hemisphere) -> (f64, f64) {
+    let ecc_sq = WGS84_ECC_SQ;
+    let ecc_prime_sq = ecc_sq / (1.0 - ecc_sq);
+
+    let x = x - 500_000.0;
+    let y = if hemi == Hemisphere::South {
+        y - 10_000_000.0
+    } else {
+        y
+    };
+
+    let m = y / UTM_K0;
+    let mu = m
+        / (WGS84_A * (1.0 - ecc_sq / 4.0 - 3.0 * ecc_sq.powi(2) / 64.0 - 5.0 * ecc_sq.powi(3) / 256.0));
+
+    let e1 = (1.0 - (1.0 - ecc_sq).sqrt()) / (1.0 + (1.0 - ecc_sq).sqrt());
+
+    let j1 = 3.0 * e1 / 2.0 - 27.0 * e1.powi(3) / 32.0;
+    let j2 = 21.0 * e1.powi(2) / 16.0 - 55.0 * e1.powi(4) / 32.0;
+    let j3 = 151.0 * e1.powi(3) / 96.0;
+    let j4 = 1097.0 * e1.powi(4) / 512.0;
+
+    let fp = mu
+        + j1 * (2.0 * mu).sin()
+        + j2 * (4.0 * mu).sin()
+        + j3 * (6.0 * mu).sin()
+        + j4 * (8.0 * mu).sin();
+
+    let c1 = ecc_prime_sq * fp.cos().powi(2);
+    let t1 = fp.tan().powi(2);
+    let n1 = WGS84_A / (1.0 - ecc_sq * fp.sin().powi(2)).sqrt();
+    let r1 = WGS84_A * (1.0 - ecc_sq) / (1.0 - ecc_sq * fp.sin().powi(2)).powf(1.5);
+    let d = x / (n1 * UTM_K0);
+
+    let lat_rad = fp
+        - (n1 * fp.tan() / r1)
+            * (d.powi(2) / 2.0
+                - (5.0 + 3.0 * t1 + 10.0 * c1 - 4.0 * c1.powi(2) - 9.0 * ecc_prime_sq) * d.powi(4) / 24.0
+                + (61.0 + 90.0 * t1 + 298.0 * c1 + 45.0 * t1.powi(2) - 252.0 * ecc_prime_sq
+                    - 3.0 * c1.powi(2))
+                    * d.powi(6)
+                    / 720.0);
+
+    let lon0_rad = utm_central_meridian_deg(zone).to_radians();
+    let lon_rad = lon0_rad
+        + (d - (1.0 + 2.0 * t1 + c1) * d.powi(3) / 6.0
+            + (5.0 - 2.0 * c1 + 28.0 * t1 - 3.0 * c1.powi(2) + 8.0 * ecc_prime_sq + 24.0 * t1.powi(2))
+                * d.powi(5)
+                / 120.0)
+            / fp.cos();
+
+    (lon_rad.to_degrees(), lat_rad.to_degrees())
+}
+
+// Meridian convergence (the angle between grid north and true north, degrees)
+// and point scale factor for a UTM coordinate. Both are first-order Snyder
+// approximations, accurate close to the central meridian.
+fn utm_convergence_and_scale(lon: f64, lat: f64, zone: u8) -> (f64, f64) {
+    let lat_rad = lat.to_radians();
+    let delta_lambda = (lon - utm_central_meridian_deg(zone)).to_radians();
+
+    let convergence = (delta_lambda.tan() * lat_rad.sin()).atan();
+    let scale = UTM_K0 * (1.0 + lat_rad.cos().powi(2) * delta_lambda.powi(2) / 2.0);
+
+    (convergence.to_degrees(), scale)
+}
 
 #[derive(PartialEq, Eq, Copy, Clone)]
 enum Hemisphere {
@@ -45,13 +169,6 @@ impl Hemisphere {
             Hemisphere::South => "S",
         }
     }
-
-    fn as_proj(&self) -> &'static str {
-        match self {
-            Hemisphere::North => "",
-            Hemisphere::South => "+south",
-        }
-    }
 }
 
 fn lonlat_to_utm_zone(lon: f64, lat: f64) -> (u8, Hemisphere) {
@@ -60,6 +177,120 @@ fn lonlat_to_utm_zone(lon: f64, lat: f64) -> (u8, Hemisphere) {
     (z, h)
 }
 
+// EPSG/SRID codes for WGS84 UTM: 326xx is zone xx north, 327xx is zone xx
+// south. These two functions convert between that numbering and our
+// (zone, hemisphere) representation.
+fn epsg_to_utm_zone(code: u32) -> Option<(u8, Hemisphere)> {
+    match code {
+        32601..=32660 => Some(((code - 32600) as u8, Hemisphere::North)),
+        32701..=32760 => Some(((code - 32700) as u8, Hemisphere::South)),
+        _ => None,
+    }
+}
+
+fn utm_zone_epsg_code(zone: u8, hemi: Hemisphere) -> u32 {
+    let base = match hemi {
+        Hemisphere::North => 32600,
+        Hemisphere::South => 32700,
+    };
+    base + zone as u32
+}
+
+// UTM only covers 80°S..84°N; beyond that, UPS takes over.
+fn is_polar(lat: f64) -> bool {
+    !(-80.0..=84.0).contains(&lat)
+}
+
+const UPS_K0: f64 = 0.994;
+const UPS_FALSE_EASTING: f64 = 2_000_000.0;
+const UPS_FALSE_NORTHING: f64 = 2_000_000.0;
+
+// Forward polar stereographic (Snyder, variant with a scale factor given at
+// the pole rather than a secant standard parallel): WGS84 lon/lat (degrees)
+// -> UPS easting/northing (meters).
+fn forward_polar_stereographic(lon: f64, lat: f64) -> (f64, f64) {
+    let e = WGS84_ECC_SQ.sqrt();
+    let lat_rad = lat.to_radians().abs();
+    let lon_rad = lon.to_radians();
+
+    let t = (std::f64::consts::FRAC_PI_4 - lat_rad / 2.0).tan()
+        / (((1.0 - e * lat_rad.sin()) / (1.0 + e * lat_rad.sin())).powf(e / 2.0));
+
+    let rho =
+        2.0 * WGS84_A * UPS_K0 * t / ((1.0 + e).powf(1.0 + e) * (1.0 - e).powf(1.0 - e)).sqrt();
+
+    let (x, y) = if lat >= 0.0 {
+        (rho * lon_rad.sin(), -rho * lon_rad.cos())
+    } else {
+        (rho * lon_rad.sin(), rho * lon_rad.cos())
+    };
+
+    (x + UPS_FALSE_EASTING, y + UPS_FALSE_NORTHING)
+}
+
+// Inverse polar stereographic: UPS easting/northing (meters) -> WGS84
+// lon/lat (degrees). `hemi` picks which pole the point is referenced to.
+fn inverse_polar_stereographic(x: f64, y: f64, hemi: Hemisphere) -> (f64, f64) {
+    let e = WGS84_ECC_SQ.sqrt();
+    let x = x - UPS_FALSE_EASTING;
+    let y = y - UPS_FALSE_NORTHING;
+
+    let rho = (x * x + y * y).sqrt();
+    let t =
+        rho * ((1.0 + e).powf(1.0 + e) * (1.0 - e).powf(1.0 - e)).sqrt() / (2.0 * WGS84_A * UPS_K0);
+
+    let chi = std::f64::consts::FRAC_PI_2 - 2.0 * t.atan();
+
+    let e2 = WGS84_ECC_SQ;
+    let e4 = e2 * e2;
+    let e6 = e4 * e2;
+    let e8 = e4 * e4;
+
+    let lat_rad = chi
+        + (e2 / 2.0 + 5.0 * e4 / 24.0 + e6 / 12.0 + 13.0 * e8 / 360.0) * (2.0 * chi).sin()
+        + (7.0 * e4 / 48.0 + 29.0 * e6 / 240.0 + 811.0 * e8 / 11520.0) * (4.0 * chi).sin()
+        + (7.0 * e6 / 120.0 + 81.0 * e8 / 1120.0) * (6.0 * chi).sin()
+        + (4279.0 * e8 / 161280.0) * (8.0 * chi).sin();
+
+    let lon_rad = if rho < 1e-9 {
+        0.0
+    } else if hemi == Hemisphere::North {
+        x.atan2(-y)
+    } else {
+        x.atan2(y)
+    };
+
+    let lat_rad = if hemi == Hemisphere::North {
+        lat_rad
+    } else {
+        -lat_rad
+    };
+
+    (lon_rad.to_degrees(), lat_rad.to_degrees())
+}
+
+// The polar zone letter distinguishes the two halves of each pole's UPS
+// square: A/B west/east of the false easting at the south pole, Y/Z at the
+// north pole.
+fn ups_zone_letter(hemi: Hemisphere, easting: f64) -> char {
+    match hemi {
+        Hemisphere::South => {
+            if easting < UPS_FALSE_EASTING {
+                'A'
+            } else {
+                'B'
+            }
+        }
+        Hemisphere::North => {
+            if easting < UPS_FALSE_EASTING {
+                'Y'
+            } else {
+                'Z'
+            }
+        }
+    }
+}
+
 struct UTMCoord {
     zone: u8,
     hemi: Hemisphere,
@@ -80,36 +311,73 @@ impl UTMCoord {
 
 impl fmt::Display for UTMCoord {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}{} {} {}",
-            self.zone,
-            self.hemi.as_str(),
-            self.x as u64,
-            self.y as u64
-        )
+        if self.zone == 0 {
+            write!(
+                f,
+                "UPS {}{} {} {}",
+                self.hemi.as_str(),
+                ups_zone_letter(self.hemi, self.x),
+                self.x as u64,
+                self.y as u64
+            )
+        } else {
+            // Signed: a non-canonical `--zone` frame can push the easting
+            // negative, and that's meaningful distortion information, not
+            // something to clamp away.
+            write!(
+                f,
+                "{}{} {} {}",
+                self.zone,
+                self.hemi.as_str(),
+                self.x.round() as i64,
+                self.y.round() as i64
+            )
+        }
     }
 }
 
 impl From<&LonLatCoord> for UTMCoord {
     fn from(source: &LonLatCoord) -> Self {
-        let (zone, hemi) = lonlat_to_utm_zone(source.lon, source.lat);
+        if is_polar(source.lat) {
+            let hemi = Hemisphere::from_lat(&source.lat);
+            let (x, y) = forward_polar_stereographic(source.lon, source.lat);
 
-        let src_proj: String = "+proj=lonlat".to_string();
-        let dst_proj: String = format!("+proj=utm +zone={} {}", zone, hemi.as_proj());
+            return UTMCoord { zone: 0, hemi, x, y };
+        }
 
-        let utm_to_longlat = Proj::new_known_crs(&src_proj, &dst_proj, None).unwrap();
-        let (utm_x, utm_y) = utm_to_longlat.convert((source.lon, source.lat)).unwrap();
+        let (zone, hemi) = lonlat_to_utm_zone(source.lon, source.lat);
+        let (x, y) = forward_transverse_mercator(source.lon, source.lat, zone);
 
-        UTMCoord {
-            zone,
-            hemi,
-            x: utm_x,
-            y: utm_y,
-        }
+        UTMCoord { zone, hemi, x, y }
     }
 }
 
+// Decompose an angle in decimal degrees into (degrees, minutes, seconds) of
+// its absolute value, for DMS display. Seconds are rounded to 0.1″ first and
+// the result carried into minutes/degrees so formatting never prints 60.0″
+// or 60′.
+fn decimal_degrees_to_dms(value: f64) -> (u32, u32, f64) {
+    let value = value.abs();
+    let degrees = value.trunc();
+    let minutes_full = (value - degrees) * 60.0;
+    let minutes = minutes_full.trunc();
+    let mut seconds = ((minutes_full - minutes) * 60.0 * 10.0).round() / 10.0;
+
+    let mut minutes = minutes as u32;
+    let mut degrees = degrees as u32;
+
+    if seconds >= 60.0 {
+        seconds -= 60.0;
+        minutes += 1;
+    }
+    if minutes >= 60 {
+        minutes -= 60;
+        degrees += 1;
+    }
+
+    (degrees, minutes, seconds)
+}
+
 struct LonLatCoord {
     lon: f64,
     lat: f64,
@@ -135,7 +403,15 @@ impl LonLatCoord {
         let tidy_lon: String = format!("{:.5}", self.lon);
         let tidy_lat: String = format!("{:.5}", self.lat);
 
-        format!("Lon, lat: {tidy_lon}, {tidy_lat}\nLat/lon: {tidy_lat}/{tidy_lon}")
+        let (lat_deg, lat_min, lat_sec) = decimal_degrees_to_dms(self.lat);
+        let (lon_deg, lon_min, lon_sec) = decimal_degrees_to_dms(self.lon);
+        let lat_hemi = if self.lat < 0.0 { "S" } else { "N" };
+        let lon_hemi = if self.lon < 0.0 { "W" } else { "E" };
+
+        format!(
+            "Lon, lat: {tidy_lon}, {tidy_lat}\nLat/lon: {tidy_lat}/{tidy_lon}\nDMS: {}°{:02}′{:04.1}″{} {}°{:02}′{:04.1}″{}",
+            lat_deg, lat_min, lat_sec, lat_hemi, lon_deg, lon_min, lon_sec, lon_hemi
+        )
     }
 }
 
@@ -145,27 +421,17 @@ impl fmt::Display for LonLatCoord {
     }
 }
 
-impl From<&UTMCoord> for LonLatCoord {
-    fn from(source: &UTMCoord) -> Self {
-        let src_proj: String = format!("+proj=utm +zone={} {}", source.zone, source.hemi.as_proj());
-        let dst_proj: String = "+proj=lonlat".to_string();
-
-        let lonlat_to_utm = match Proj::new_known_crs(&src_proj, &dst_proj, None) {
-            Ok(transform) => transform,
-            Err(e) => panic!("Proj failed to make a transformer from “{}” to “{}”: {}", src_proj, dst_proj, e)
-        };
+impl TryFrom<&UTMCoord> for LonLatCoord {
+    type Error = anyhow::Error;
 
-        let (lon, lat) = match lonlat_to_utm.convert((source.x, source.y)) {
-            Ok((x, y)) => (x, y),
-            Err(e) => panic!("Proj failed to convert: {}", e)
+    fn try_from(source: &UTMCoord) -> Result<Self> {
+        let (lon, lat) = if source.zone == 0 {
+            inverse_polar_stereographic(source.x, source.y, source.hemi)
+        } else {
+            inverse_transverse_mercator(source.x, source.y, source.zone, source.hemi)
         };
 
-        match LonLatCoord::new(lon, lat) {
-            Ok(ll) => { ll },
-            Err(e) => {
-                panic!("Lon/lat out of bounds: {}", e)
-            }
-        }
+        LonLatCoord::new(lon, lat).context("That UTM/MGS/MGRS coordinate doesn't land on the globe")
     }
 }
 
@@ -180,12 +446,16 @@ impl MGSCoord {
         self.key.into_iter().map(|c| c.to_string()).collect()
     }
 
-    fn from_u8_and_str(zone: u8, key_string: &str) -> MGSCoord {
+    fn from_u8_and_str(zone: u8, key_string: &str) -> Result<MGSCoord> {
         let mut key: [u8; 12] = [0; 12];
         for (i, c) in key_string.chars().enumerate() {
-            key[i] = c.to_digit(10).expect("Quadkey digit not in 0..3!") as u8;
+            let digit = c.to_digit(10).filter(|d| *d <= 3).context(format!(
+                "Expected a quadkey digit in 0..3 but got “{}” in “{}”.",
+                c, key_string
+            ))?;
+            key[i] = digit as u8;
         }
-        MGSCoord { zone, key }
+        Ok(MGSCoord { zone, key })
     }
 }
 
@@ -255,7 +525,10 @@ impl From<&MGSCoord> for UTMCoord {
                     iy |= mask;
                 }
                 _ => {
-                    panic!("Quadkey digit not in 0..3!")
+                    // MGSCoord::from_u8_and_str validates digits to 0..=3 at
+                    // parse time, and the forward conversion above this impl
+                    // only ever produces digits in that range.
+                    unreachable!("Quadkey digit not in 0..3!")
                 }
             }
         }
@@ -291,6 +564,273 @@ impl From<&MGSCoord> for UTMCoord {
     }
 }
 
+// Latitude band letters for standard MGRS, 8° bands from 80°S, skipping I
+// and O (the band boundaries below 'X' are the band's minimum latitude; 'Z'
+// here is a sentinel marking the top of the 'X' band, not a real letter).
+const MGRS_LAT_BANDS: [(char, f64); 21] = [
+    ('C', -80.0),
+    ('D', -72.0),
+    ('E', -64.0),
+    ('F', -56.0),
+    ('G', -48.0),
+    ('H', -40.0),
+    ('J', -32.0),
+    ('K', -24.0),
+    ('L', -16.0),
+    ('M', -8.0),
+    ('N', 0.0),
+    ('P', 8.0),
+    ('Q', 16.0),
+    ('R', 24.0),
+    ('S', 32.0),
+    ('T', 40.0),
+    ('U', 48.0),
+    ('V', 56.0),
+    ('W', 64.0),
+    ('X', 72.0),
+    ('Z', 84.0),
+];
+
+fn mgrs_lat_band(lat: f64) -> Result<char> {
+    for window in MGRS_LAT_BANDS.windows(2) {
+        let (letter, min_lat) = window[0];
+        let (_, max_lat) = window[1];
+        if lat >= min_lat && lat < max_lat {
+            return Ok(letter);
+        }
+    }
+    // Roundtripping through a forward/inverse projection pair can nudge a
+    // latitude sitting exactly on the 84°N edge a few ulps past it.
+    if (84.0..84.001).contains(&lat) {
+        return Ok('X');
+    }
+    Err(anyhow!(
+        "Latitude {} is outside the UTM/MGRS band range (80°S..84°N).",
+        lat
+    ))
+}
+
+// Standard MGRS 100 000 m square identification. The column alphabet runs
+// the full A..Z (24 letters, I and O skipped); the row alphabet only runs
+// A..V (20 letters, likewise skipped). Each zone's column/row origin shifts
+// so that identical squares in neighboring zones don't repeat nearby.
+#[derive(Copy, Clone)]
+struct MGRSCoord {
+    zone: u8,
+    band: char,
+    hemi: Hemisphere,
+    easting: f64,
+    northing: f64,
+}
+
+impl MGRSCoord {
+    fn column_letters() -> &'static [u8] {
+        b"ABCDEFGHJKLMNPQRSTUVWXYZ"
+    }
+
+    fn row_letters() -> &'static [u8] {
+        b"ABCDEFGHJKLMNPQRSTUV"
+    }
+
+    fn column_origin_index(zone: u8) -> i64 {
+        match zone % 3 {
+            1 => 0,  // 'A'
+            2 => 8,  // 'J'
+            _ => 16, // 'S'
+        }
+    }
+
+    fn row_origin_index(zone: u8) -> i64 {
+        if zone % 2 == 1 {
+            0 // 'A'
+        } else {
+            5 // 'F'
+        }
+    }
+
+    fn hemisphere_for_band(band: char) -> Hemisphere {
+        if band < 'N' {
+            Hemisphere::South
+        } else {
+            Hemisphere::North
+        }
+    }
+
+    // The lowest northing that can legitimately appear in `band`, found by
+    // running the band's southern edge through the forward transverse
+    // Mercator series at this zone's central meridian and at its edge (south
+    // of the equator, northing at a fixed latitude drops away from the
+    // central meridian). Used to un-rollover the 2 000 000 m-periodic row
+    // letters back onto the right 2 000 km band.
+    fn min_northing_for_band(zone: u8, band: char) -> Result<f64> {
+        let band_min_lat = MGRS_LAT_BANDS
+            .iter()
+            .find(|(letter, _)| *letter == band)
+            .map(|(_, lat)| *lat)
+            .ok_or_else(|| anyhow!("“{}” is not a valid MGRS latitude band letter.", band))?;
+
+        let central_lon = utm_central_meridian_deg(zone);
+        let (_, at_center) = forward_transverse_mercator(central_lon, band_min_lat, zone);
+        let (_, at_edge) = forward_transverse_mercator(central_lon - 3.0, band_min_lat, zone);
+        Ok(at_center.min(at_edge))
+    }
+
+    fn square_id(&self) -> (char, char) {
+        let columns = Self::column_letters();
+        let rows = Self::row_letters();
+
+        let col_cell = (self.easting / 100_000.0).floor() as i64;
+        let col_index =
+            (Self::column_origin_index(self.zone) + col_cell - 1).rem_euclid(columns.len() as i64);
+
+        let row_cell = (self.northing / 100_000.0).floor() as i64;
+        let row_index = (Self::row_origin_index(self.zone) + row_cell).rem_euclid(rows.len() as i64);
+
+        (
+            columns[col_index as usize] as char,
+            rows[row_index as usize] as char,
+        )
+    }
+
+    fn as_string(&self, precision: usize) -> String {
+        let (col, row) = self.square_id();
+        let digits = precision.min(5);
+        let scale = 10f64.powi(5 - digits as i32);
+
+        let easting_in_square = (self.easting.rem_euclid(100_000.0) / scale) as u64;
+        let northing_in_square = (self.northing.rem_euclid(100_000.0) / scale) as u64;
+
+        format!(
+            "{}{}{}{}{:0width$}{:0width$}",
+            self.zone,
+            self.band,
+            col,
+            row,
+            easting_in_square,
+            northing_in_square,
+            width = digits
+        )
+    }
+
+    fn parse(s: &str) -> Result<MGRSCoord> {
+        let s = s.trim();
+
+        let band_pos = s
+            .find(|c: char| c.is_ascii_alphabetic())
+            .context(format!(
+                "Expected an MGRS reference like 14QMG9016833666 but got “{}”.",
+                s
+            ))?;
+
+        let zone = s[..band_pos]
+            .parse::<u8>()
+            .context(format!("Expected a UTM zone in 01..60 at the start of “{}”.", s))?;
+
+        let letters = &s[band_pos..];
+        ensure!(
+            letters.len() >= 3,
+            format!(
+                "Expected a latitude band and a two-letter 100 000 m square id in “{}”.",
+                s
+            )
+        );
+
+        let mut letter_chars = letters.chars();
+        let band = letter_chars.next().unwrap().to_ascii_uppercase();
+        let col = letter_chars.next().unwrap().to_ascii_uppercase();
+        let row = letter_chars.next().unwrap().to_ascii_uppercase();
+
+        let digits = &letters[3..];
+        ensure!(
+            !digits.is_empty() && digits.len().is_multiple_of(2) && digits.len() <= 10,
+            format!(
+                "Expected an even number (2-10) of easting/northing digits but got “{}” (length {}).",
+                digits,
+                digits.len()
+            )
+        );
+
+        let precision = digits.len() / 2;
+        let (easting_digits, northing_digits) = digits.split_at(precision);
+
+        let easting_value = easting_digits.parse::<f64>().context(format!(
+            "Expected numeric easting digits but got “{}”.",
+            easting_digits
+        ))?;
+        let northing_value = northing_digits.parse::<f64>().context(format!(
+            "Expected numeric northing digits but got “{}”.",
+            northing_digits
+        ))?;
+        let scale = 10f64.powi(5 - precision as i32);
+
+        let columns = Self::column_letters();
+        let rows = Self::row_letters();
+
+        let col_index = columns
+            .iter()
+            .position(|&c| c == col as u8)
+            .context(format!(
+                "“{}” is not a valid MGRS column letter (I and O are skipped).",
+                col
+            ))? as i64;
+        let row_index = rows
+            .iter()
+            .position(|&c| c == row as u8)
+            .context(format!(
+                "“{}” is not a valid MGRS row letter (I and O are skipped).",
+                row
+            ))? as i64;
+
+        let col_cell = (col_index - Self::column_origin_index(zone)).rem_euclid(columns.len() as i64) + 1;
+        let easting = col_cell as f64 * 100_000.0 + easting_value * scale;
+
+        let row_cell = (row_index - Self::row_origin_index(zone)).rem_euclid(rows.len() as i64);
+        let mut northing = row_cell as f64 * 100_000.0 + northing_value * scale;
+
+        let min_northing = Self::min_northing_for_band(zone, band)?;
+        while northing < min_northing {
+            northing += 2_000_000.0;
+        }
+
+        Ok(MGRSCoord {
+            zone,
+            band,
+            hemi: Self::hemisphere_for_band(band),
+            easting,
+            northing,
+        })
+    }
+}
+
+impl fmt::Display for MGRSCoord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_string(5))
+    }
+}
+
+impl TryFrom<&UTMCoord> for MGRSCoord {
+    type Error = anyhow::Error;
+
+    fn try_from(source: &UTMCoord) -> Result<Self> {
+        let ll = LonLatCoord::try_from(source)?;
+        let band = mgrs_lat_band(ll.lat)?;
+
+        Ok(MGRSCoord {
+            zone: source.zone,
+            band,
+            hemi: source.hemi,
+            easting: source.x,
+            northing: source.y,
+        })
+    }
+}
+
+impl From<&MGRSCoord> for UTMCoord {
+    fn from(source: &MGRSCoord) -> Self {
+        UTMCoord::new(&source.zone, &source.hemi, &source.easting, &source.northing)
+    }
+}
+
 fn help() {
     eprintln!(
         "\
@@ -300,15 +840,34 @@ three.
 
 Usage (matched by argument count):
 oö <MGS cell in ZZ/QKQKQKQKQKQK format>
+oö <MGRS reference, e.g. 14QMG9016833666>
 oö <longitude> <latitude>
 oö <UTM zone> <easting> <northing>
+oö <UTM EPSG/SRID code, e.g. 32614> <easting> <northing>
+oö <0N or 0S, for UPS> <easting> <northing>
+
+With no coordinate arguments (or under --stdin), oö reads whitespace-\
+separated coordinates from standard input instead, one set per line, and \
+prints one result block per line. A line that fails to parse is reported \
+to stderr with its line number; the rest of the batch still runs.
+
+Flags:
+--zone N  Additionally report the UTM and MGS forms in zone N, even when \
+it isn’t the canonical zone for the coördinate. Useful when deliberately \
+working in one reference zone, e.g. stepping an MGS quadkey across a zone \
+edge.
+--stdin   Read coordinates from standard input even if arguments are also \
+given (they are otherwise ignored in this mode).
 
 Example:
 $ oö -99.09357951534054 19.29675919163688
 Lon, lat: -99.09358, 19.29676
 Lat/lon: 19.29676/-99.09358
-UTM 14N 490168 2133666
+DMS: 19°17′48.3″N 99°05′36.9″W
+14N 490168 2133666 (EPSG:32614)
+Convergence -0.0309°, scale 0.999601
 14/033113131312
+MGRS 14QMG9016833666
 
 Conventions:
 1. MGS cells are treated as their centers in conversions, and are read and \
@@ -316,18 +875,73 @@ written only at level 12.
 2. WGS84 (lon/lat) and UTM coordinates are read at any (float64) precision \
 but written at ~1 meter precision (integer for UTM, 5 decimals for WGS84).
 3. MGS and UTM coördinates are read in any zone but written in their \
-canonical zone."
+canonical zone.
+4. MGRS references are read at any precision (1-5 digit easting/northing \
+pairs) but written at 1 meter precision (5-digit pairs).
+5. Latitudes above 84°N or below 80°S are outside UTM's valid range and are \
+read and written as UPS (zone 0) instead, with a polar zone letter (A/B \
+south, Y/Z north) in place of a band letter. MGS and MGRS don't apply at \
+the poles.
+6. --zone does not affect MGRS output, since MGRS is a global reference \
+system (it encodes its own zone) rather than a zone-relative one like MGS.
+7. Convergence (grid north minus true north) and scale (grid distance to \
+ground distance ratio) are first-order approximations for the canonical UTM \
+zone; they are not reported for UPS points, which have no meridian \
+convergence in the UTM sense.
+8. UTM zones accept EPSG/SRID codes (326xx north, 327xx south) as well as \
+the zone-plus-hemisphere form, and the EPSG code of the canonical zone is \
+always reported alongside it. UPS and MGS/MGRS have no EPSG codes here.
+9. In batch mode, each input line is matched by whitespace-token count \
+exactly as command-line arguments are, so it accepts the same MGS, MGRS, \
+lon/lat, UTM, and UPS forms. --zone applies to every line in the batch."
     );
 }
 
-fn make_message(ll: LonLatCoord) -> Result<String, anyhow::Error> {
+fn make_message(ll: LonLatCoord, alt_zone: Option<u8>) -> Result<String, anyhow::Error> {
     let utm = UTMCoord::from(&ll);
+
+    // MGS and MGRS are both built on UTM zones, which don't reach the poles;
+    // UPS points (zone 0) only get the lon/lat and UPS lines.
+    if utm.zone == 0 {
+        return Ok(format!("{}\n{}", ll.as_deluxe_string(), utm));
+    }
+
     let mgs = MGSCoord::from(&utm);
+    let mgrs = MGRSCoord::try_from(&utm)?;
+    let (convergence, scale) = utm_convergence_and_scale(ll.lon, ll.lat, utm.zone);
+    let epsg = utm_zone_epsg_code(utm.zone, utm.hemi);
+
+    let mut message = format!(
+        "{}\n{} (EPSG:{})\nConvergence {:+.4}°, scale {:.6}\n{}\nMGRS {}",
+        ll.as_deluxe_string(),
+        utm,
+        epsg,
+        convergence,
+        scale,
+        mgs,
+        mgrs
+    );
+
+    if let Some(zone) = alt_zone {
+        let hemi = Hemisphere::from_lat(&ll.lat);
+        let (x, y) = forward_transverse_mercator(ll.lon, ll.lat, zone);
+        let utm_alt = UTMCoord::new(&zone, &hemi, &x, &y);
+        let mgs_alt = MGSCoord::from(&utm_alt);
+
+        message.push_str(&format!(
+            "\nIn zone {} (requested; canonical zone is {}): {} (EPSG:{})\n{}",
+            zone,
+            utm.zone,
+            utm_alt,
+            utm_zone_epsg_code(zone, hemi),
+            mgs_alt
+        ));
+    }
 
-    Ok(format!("{}\n{}\n{}", ll.as_deluxe_string(), utm, mgs))
+    Ok(message)
 }
 
-fn from_ll(argv: Vec<String>) -> Result<String> {
+fn from_ll(argv: Vec<String>, alt_zone: Option<u8>) -> Result<String> {
     let lon = argv[1].parse::<f64>().context(format!(
         "Expected a numeric longitude but got “{}”.",
         argv[1]
@@ -342,10 +956,10 @@ fn from_ll(argv: Vec<String>) -> Result<String> {
         lon, lat
     ))?;
 
-    make_message(ll)
+    make_message(ll, alt_zone)
 }
 
-fn from_mgs(argv: Vec<String>) -> Result<String, anyhow::Error> {
+fn from_mgs(argv: Vec<String>, alt_zone: Option<u8>) -> Result<String, anyhow::Error> {
     let (z, c) = argv[1]
         .split_once('/')
         .context("With one argument, expected an MGS tile like 42/012301230123, with the slash.")?;
@@ -363,44 +977,74 @@ fn from_mgs(argv: Vec<String>) -> Result<String, anyhow::Error> {
     );
 
     // Please see the UTM normalization comment in from_utm().
-    let mgs: MGSCoord = MGSCoord::from_u8_and_str(zone, c);
+    let mgs: MGSCoord = MGSCoord::from_u8_and_str(zone, c)?;
     let utm = UTMCoord::from(&mgs);
-    let ll = LonLatCoord::from(&utm);
+    let ll = LonLatCoord::try_from(&utm)?;
+
+    make_message(ll, alt_zone)
+}
+
+fn from_mgrs(argv: Vec<String>, alt_zone: Option<u8>) -> Result<String> {
+    let mgrs = MGRSCoord::parse(&argv[1])?;
+    let utm = UTMCoord::from(&mgrs);
+    let ll = LonLatCoord::try_from(&utm)?;
 
-    make_message(ll)
+    make_message(ll, alt_zone)
 }
 
-fn from_utm(argv: Vec<String>) -> Result<String> {
+fn from_utm(argv: Vec<String>, alt_zone: Option<u8>) -> Result<String> {
     let zone_string = &argv[1];
 
-    ensure!(
-        (1..=3).contains(&zone_string.len()),
-        format!(
-            "Expected a UTM zone like 1, 23N, or 42S, but got {}.",
+    // A 5-digit zone argument is an EPSG/SRID code (326xx north, 327xx
+    // south) rather than a bare zone number, e.g. `32614` instead of `14N`.
+    let (zone, hemi) = if zone_string.len() == 5 {
+        let epsg = zone_string.parse::<u32>().context(format!(
+            "Expected a 5-digit EPSG/SRID code like 32614, but got {}.",
             zone_string
-        )
-    );
-
-    let last_character = zone_string.chars().last().unwrap();
-    let (zone, hemi) = match last_character {
-        'N' | 'S' => (
-            zone_string[..zone_string.len() - 1]
-                .parse::<u8>()
-                .context(format!(
-                    "Expected an integer UTM zone (with optional N/S), but got {}.",
+        ))?;
+
+        epsg_to_utm_zone(epsg).context(format!(
+            "Expected an EPSG/SRID code in 32601..32660 (UTM north) or \
+             32701..32760 (UTM south), but got {}.",
+            epsg
+        ))?
+    } else {
+        ensure!(
+            (1..=3).contains(&zone_string.len()),
+            format!(
+                "Expected a UTM zone like 1, 23N, 42S, or an EPSG/SRID code like 32614, but got {}.",
+                zone_string
+            )
+        );
+
+        let last_character = zone_string.chars().last().unwrap();
+        match last_character {
+            'N' | 'S' => (
+                zone_string[..zone_string.len() - 1]
+                    .parse::<u8>()
+                    .context(format!(
+                        "Expected an integer UTM zone (with optional N/S), but got {}.",
+                        zone_string
+                    ))?,
+                Hemisphere::from_char(&last_character).unwrap(), // infallible given match
+            ),
+            _ => (
+                zone_string.parse::<u8>().context(format!(
+                    "Expected a UTM zone like 1, 23N, 42S, or an EPSG/SRID code like 32614, but got {}.",
                     zone_string
                 ))?,
-            Hemisphere::from_char(&last_character).unwrap(), // infallible given match
-        ),
-        _ => (
-            zone_string.parse::<u8>().context(format!(
-                "Expected a UTM zone like 1, 23N, or 42S, but got {}.",
-                zone_string
-            ))?,
-            Hemisphere::North,
-        ),
+                Hemisphere::North,
+            ),
+        }
     };
 
+    // Zone 0 is UPS (see inverse_polar_stereographic via LonLatCoord::try_from).
+    ensure!(
+        (0..=60).contains(&zone),
+        "Expected a UTM zone in 01..60 (or 0 for UPS) but got {}.",
+        zone
+    );
+
     let x = argv[2]
         .parse::<f64>()
         .context(format!("Expected numeric UTM easting but got {}", argv[2]))?;
@@ -466,32 +1110,145 @@ fn from_utm(argv: Vec<String>) -> Result<String> {
     This is the behavior that I want for my very specific purposes, but
     anyone building on my work should understand that it’s a tricky question
     and the best answer for them may be different.
+
+    For the case where a user really does want a specific, non-canonical
+    zone (the cell-neighbor workflow above, for instance), `--zone N` asks
+    make_message to additionally report the UTM and MGS forms in zone N,
+    alongside the canonical ones computed here.
     */
 
     let utm = UTMCoord::new(&zone, &hemi, &x, &y);
-    let ll = LonLatCoord::from(&utm);
-    make_message(ll)
+    let ll = LonLatCoord::try_from(&utm)?;
+    make_message(ll, alt_zone)
+}
+
+// Pulls the first `--zone N` out of `args`, wherever it appears, and returns the
+// requested zone. Mutates `args` so the remaining positional arguments keep
+// their usual meaning for the argument-count dispatch in main().
+fn extract_zone_flag(args: &mut Vec<String>) -> Result<Option<u8>> {
+    let Some(pos) = args.iter().position(|a| a == "--zone") else {
+        return Ok(None);
+    };
+
+    let zone_string = args
+        .get(pos + 1)
+        .context("--zone requires a UTM zone number, e.g. --zone 14.")?
+        .clone();
+
+    let zone = zone_string.parse::<u8>().context(format!(
+        "Expected a UTM zone in 01..60 after --zone but got “{}”.",
+        zone_string
+    ))?;
+
+    ensure!(
+        (1..=60).contains(&zone),
+        "Expected a UTM zone in 01..60 after --zone but got “{}”.",
+        zone_string
+    );
+
+    args.drain(pos..=pos + 1);
+
+    Ok(Some(zone))
+}
+
+// Pulls the first `--stdin` out of `args`, wherever it appears.
+fn extract_stdin_flag(args: &mut Vec<String>) -> bool {
+    let Some(pos) = args.iter().position(|a| a == "--stdin") else {
+        return false;
+    };
+
+    args.remove(pos);
+    true
+}
+
+// Matches `argv` (argv[0] is an unused placeholder, as in std::env::args())
+// by token count, exactly as main() matches real command-line arguments.
+// Shared by single-shot and batch (stdin) modes.
+fn dispatch(argv: Vec<String>, alt_zone: Option<u8>) -> Result<String> {
+    match argv.len() - 1 {
+        1 => {
+            if argv[1].contains('/') {
+                from_mgs(argv, alt_zone)
+            } else {
+                from_mgrs(argv, alt_zone)
+            }
+        }
+        2 => from_ll(argv, alt_zone),
+        3 => from_utm(argv, alt_zone),
+        _ => Err(anyhow!(
+            "Expected 1 argument (MGS cell or MGRS reference), 2 (lon lat), or 3 (UTM), but got {}.\nSee --help.",
+            argv.len() - 1
+        )),
+    }
+}
+
+// Batch mode: reads whitespace-separated coordinates from stdin, one set per
+// line, and prints a result block for each. A line that fails to parse is
+// reported to stderr with its line number; the rest of the batch still runs.
+fn run_batch(alt_zone: Option<u8>) {
+    // A caught panic still prints the default "thread panicked" backtrace
+    // to stderr; silence that so it doesn't drown out our own line-numbered
+    // diagnostics, then restore the previous hook once the batch is done.
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for (i, line) in io::stdin().lock().lines().enumerate() {
+        let line_number = i + 1;
+
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln!("Line {}: {}", line_number, e);
+                continue;
+            }
+        };
+
+        let tokens: Vec<String> = line.split_whitespace().map(String::from).collect();
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let mut argv = vec![String::new()];
+        argv.extend(tokens);
+
+        // dispatch() should only ever return an Err, never panic, but this
+        // is untrusted per-line input driving a lot of numeric code; a
+        // stray panic must not take the rest of the batch down with it.
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| dispatch(argv, alt_zone)));
+        match result {
+            Ok(Ok(m)) => println!("{}\n", m),
+            Ok(Err(e)) => eprintln!("Line {}: {}", line_number, e),
+            Err(_) => eprintln!("Line {}: internal error converting this coordinate.", line_number),
+        }
+    }
+
+    panic::set_hook(previous_hook);
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
 
     if args.contains(&String::from("--help")) {
         help();
         process::exit(0)
     }
 
-    let message = match args.len() - 1 {
-        1 => from_mgs(args),
-        2 => from_ll(args),
-        3 => from_utm(args),
-        _ => Err(anyhow!(
-            "Expected 1 argument (MGS coord), 2 (lon lat), or 3 (UTM), but got {}.\nSee --help.",
-            args.len() - 1
-        )),
+    let alt_zone = match extract_zone_flag(&mut args) {
+        Ok(alt_zone) => alt_zone,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1)
+        }
     };
 
-    match message {
+    let stdin_requested = extract_stdin_flag(&mut args);
+
+    if stdin_requested || args.len() == 1 {
+        run_batch(alt_zone);
+        return;
+    }
+
+    match dispatch(args, alt_zone) {
         Ok(m) => {
             println!("{}", m)
         }